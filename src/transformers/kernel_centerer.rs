@@ -0,0 +1,154 @@
+pub use super::transformer::Transformer;
+use ndarray::{Array1, Array2, ArrayBase, Axis, Data, Ix2};
+use std::fmt;
+
+/// KernelCenterer centers a precomputed kernel (Gram) matrix in feature space, without ever
+/// materializing the underlying features. This is a preprocessing step ahead of kernel PCA or
+/// kernel regression.
+#[derive(Clone, PartialEq, Default)]
+pub struct KernelCenterer {
+    // Column means of the training kernel, one per training sample.
+    k_fit_cols: Array1<f64>,
+    // Overall mean of the training kernel.
+    k_fit_all: f64,
+}
+
+impl KernelCenterer {
+    pub fn k_fit_cols(&self) -> &Array1<f64> {
+        &self.k_fit_cols
+    }
+
+    pub fn k_fit_all(&self) -> f64 {
+        self.k_fit_all
+    }
+}
+
+impl Transformer for KernelCenterer {
+    /// Returns a `KernelCenterer` fit to the training kernel `obs`, an `n_train x n_train` Gram
+    /// matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, KernelCenterer};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let k = array![[2., 1., 0.], [1., 2., 1.], [0., 1., 2.]];
+    /// let centerer = KernelCenterer::fit(&k);
+    /// assert_abs_diff_eq!(*centerer.k_fit_cols(), array![1., 1.33333333, 1.], epsilon = 1e-6);
+    /// ```
+    fn fit(obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> KernelCenterer {
+        let k_fit_cols = obs.mean_axis(Axis(0)).unwrap();
+        let k_fit_all = k_fit_cols.mean().unwrap();
+        KernelCenterer {
+            k_fit_cols,
+            k_fit_all,
+        }
+    }
+
+    /// Centers a (possibly rectangular, test-by-train) kernel `obs` in feature space.
+    ///
+    /// Returns a new owned Array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, KernelCenterer};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let k = array![[2., 1., 0.], [1., 2., 1.], [0., 1., 2.]];
+    /// let centerer = KernelCenterer::fit(&k);
+    /// let centered = centerer.transform(&k);
+    ///
+    /// // A centered Gram matrix has rows (and columns) that sum to zero.
+    /// assert_abs_diff_eq!(centered.sum_axis(ndarray::Axis(0)), array![0., 0., 0.], epsilon = 1e-10);
+    /// ```
+    ///
+    /// Centering is calculated as `K - K_pred_cols - K_fit_cols + K_fit_all`, where `K_pred_cols`
+    /// is the per-row mean of `K` broadcast across columns, and `K_fit_cols` is the per-column
+    /// mean of the training kernel broadcast across rows.
+    fn transform(&self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
+        let k_pred_cols = obs.mean_axis(Axis(1)).unwrap().insert_axis(Axis(1));
+        obs - &k_pred_cols - &self.k_fit_cols + self.k_fit_all
+    }
+
+    /// Applies `fit` and then `transform` in succession.
+    ///
+    /// Returns a new owned Array.
+    fn fit_transform(obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
+        Self::fit(obs).transform(obs)
+    }
+}
+
+impl fmt::Display for KernelCenterer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "K_fit_cols: {}\nK_fit_all: {}",
+            self.k_fit_cols, self.k_fit_all
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn fit_test() {
+        let k = array![[2., 1., 0.], [1., 2., 1.], [0., 1., 2.]];
+        let centerer = KernelCenterer::fit(&k);
+        assert_abs_diff_eq!(
+            *centerer.k_fit_cols(),
+            array![1., 1.33333333, 1.],
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(centerer.k_fit_all(), 1.11111111, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn transform_on_training_kernel_sums_to_zero() {
+        let k = array![[2., 1., 0.], [1., 2., 1.], [0., 1., 2.]];
+        let centerer = KernelCenterer::fit(&k);
+        let centered = centerer.transform(&k);
+        assert_abs_diff_eq!(
+            centered.sum_axis(Axis(0)),
+            array![0., 0., 0.],
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(
+            centered.sum_axis(Axis(1)),
+            array![0., 0., 0.],
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn fit_transform_test() {
+        let k = array![[2., 1., 0.], [1., 2., 1.], [0., 1., 2.]];
+        let centered = KernelCenterer::fit_transform(&k);
+        let expected = KernelCenterer::fit(&k).transform(&k);
+        assert_abs_diff_eq!(centered, expected, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn transform_rectangular_test_kernel() {
+        // 2 test samples against the 3 training samples above.
+        let k_train = array![[2., 1., 0.], [1., 2., 1.], [0., 1., 2.]];
+        let centerer = KernelCenterer::fit(&k_train);
+        let k_test = array![[1.5, 1., 0.5], [0.5, 1., 1.5]];
+        let centered = centerer.transform(&k_test);
+        assert_abs_diff_eq!(
+            centered,
+            array![
+                [0.611111, -0.222222, -0.388889],
+                [-0.388889, -0.222222, 0.611111]
+            ],
+            epsilon = 1e-6
+        );
+    }
+}
@@ -1,12 +1,14 @@
-use crate::transformer::Transformer;
+pub use super::transformer::Transformer;
 use ndarray::{Array1, Array2, ArrayBase, Axis, Data, Ix2};
 use ndarray_stats::QuantileExt;
+use std::fmt;
 
-/// Transforms each feature by scaling to the range [0, 1]
-
+/// Transforms each feature by scaling to the range [0, 1].
+///
 /// If x is the original feature, then the transformed feature z = (x - min(x)) / (max(x) - min(x))
 // TODO: Allow for custom range scaling instead of just [0, 1]
 // TODO: Allow for different NaN handling strategies.
+#[derive(Clone, PartialEq, Default)]
 pub struct MinMaxScaler {
     min: Array1<f64>,
     max: Array1<f64>,
@@ -19,6 +21,28 @@ impl MinMaxScaler {
     pub fn max(&self) -> &Array1<f64> {
         &self.max
     }
+
+    /// Maps `obs`, assumed to be in the [0, 1] range produced by `transform`, back to the
+    /// original feature scale.
+    ///
+    /// Returns a new owned Array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, MinMaxScaler};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let data = array![[-1., 2.], [-0.5, 6.], [0., 10.], [1., 18.]];
+    /// let mms = MinMaxScaler::fit(&data);
+    /// let scaled = mms.transform(&data);
+    /// let recovered = mms.inverse_transform(&scaled);
+    /// assert_abs_diff_eq!(recovered, data, epsilon = 1e-10);
+    /// ```
+    pub fn inverse_transform(&self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
+        obs * (self.max() - self.min()) + self.min()
+    }
 }
 
 impl Transformer for MinMaxScaler {
@@ -31,8 +55,7 @@ impl Transformer for MinMaxScaler {
     ///
     /// ```
     /// use ndarray::array;
-    /// use linfa_preprocessing::min_max_scaler::MinMaxScaler;
-    /// use linfa_preprocessing::transformer::Transformer;
+    /// use linfa_preprocessing::transformers::{Transformer, MinMaxScaler};
     /// use approx::assert_abs_diff_eq;
     ///
     /// let data = array![[1., 3., 2.], [5., 2., 1.]];
@@ -42,8 +65,8 @@ impl Transformer for MinMaxScaler {
     /// ```
     fn fit(obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> MinMaxScaler {
         MinMaxScaler {
-            min: obs.map_axis(Axis(0), |col| col.min_skipnan().clone()),
-            max: obs.map_axis(Axis(0), |col| col.max_skipnan().clone()),
+            min: obs.map_axis(Axis(0), |col| *col.min_skipnan()),
+            max: obs.map_axis(Axis(0), |col| *col.max_skipnan()),
         }
     }
 
@@ -58,8 +81,7 @@ impl Transformer for MinMaxScaler {
     ///
     /// ```
     /// use ndarray::array;
-    /// use linfa_preprocessing::min_max_scaler::MinMaxScaler;
-    /// use linfa_preprocessing::transformer::Transformer;
+    /// use linfa_preprocessing::transformers::{Transformer, MinMaxScaler};
     ///
     /// let data = array![[-1., 2.], [-0.5, 6.], [0., 10.], [1., 18.]];
     /// let mms = MinMaxScaler::fit(&data);
@@ -82,8 +104,7 @@ impl Transformer for MinMaxScaler {
     ///
     /// ```
     /// use ndarray::array;
-    /// use linfa_preprocessing::min_max_scaler::MinMaxScaler;
-    /// use linfa_preprocessing::transformer::Transformer;
+    /// use linfa_preprocessing::transformers::{Transformer, MinMaxScaler};
     /// let data = array![[-1., 2.], [-0.5, 6.], [0., 10.], [1., 18.]];
     /// let mms_data = MinMaxScaler::fit_transform(&data);
     /// assert_eq!(
@@ -96,6 +117,12 @@ impl Transformer for MinMaxScaler {
     }
 }
 
+impl fmt::Display for MinMaxScaler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Min: {}\nMax: {}", self.min, self.max)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,4 +164,13 @@ mod tests {
         assert_eq!(*mms.min(), array![1., 2., 1.]);
         assert_eq!(*mms.max(), array![5., 3., 2.]);
     }
+
+    #[test]
+    fn inverse_transform_test() {
+        let data = array![[-1., 2.], [-0.5, 6.], [0., 10.], [1., 18.]];
+        let mms = MinMaxScaler::fit(&data);
+        let scaled = mms.transform(&data);
+        let recovered = mms.inverse_transform(&scaled);
+        assert_abs_diff_eq!(recovered, data, epsilon = 1e-10);
+    }
 }
@@ -1,7 +1,13 @@
+pub mod kernel_centerer;
 pub mod min_max_scaler;
+pub mod normalizer;
+pub mod robust_scaler;
 pub mod standard_scaler;
 pub mod transformer;
 
+pub use kernel_centerer::KernelCenterer;
 pub use min_max_scaler::MinMaxScaler;
+pub use normalizer::{Norm, Normalizer};
+pub use robust_scaler::RobustScaler;
 pub use standard_scaler::StandardScaler;
 pub use transformer::Transformer;
@@ -0,0 +1,169 @@
+pub use super::transformer::Transformer;
+use ndarray::{Array2, ArrayBase, Axis, Data, Ix2};
+
+/// The per-sample norm used by [`Normalizer`] to rescale each row to unit norm.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Norm {
+    /// `sum(|x|)`
+    L1,
+    /// `sqrt(sum(x^2))`
+    #[default]
+    L2,
+    /// `max(|x|)`
+    Max,
+}
+
+impl Norm {
+    fn of(self, row: &[f64]) -> f64 {
+        match self {
+            Norm::L1 => row.iter().map(|x| x.abs()).sum(),
+            Norm::L2 => row.iter().map(|x| x * x).sum::<f64>().sqrt(),
+            Norm::Max => row.iter().fold(0., |acc: f64, x| acc.max(x.abs())),
+        }
+    }
+}
+
+/// Normalizer rescales each sample (row) to have unit norm, independently of the other samples.
+///
+/// Unlike the column-wise scalers, this is a sample-wise transform: it has no fitted state, so
+/// `fit` is a no-op. This is useful ahead of models that rely on the dot product or other
+/// similarity between samples, such as cosine similarity.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Normalizer {
+    norm: Norm,
+}
+
+impl Normalizer {
+    pub fn norm(&self) -> Norm {
+        self.norm
+    }
+
+    /// Returns a builder for configuring which [`Norm`] is used, before calling
+    /// [`NormalizerParams::fit`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, Normalizer, Norm};
+    ///
+    /// let data = array![[3., 4.]];
+    /// let normalizer = Normalizer::params().norm(Norm::L1).fit(&data);
+    /// assert_eq!(normalizer.transform(&data), array![[3. / 7., 4. / 7.]]);
+    /// ```
+    pub fn params() -> NormalizerParams {
+        NormalizerParams::default()
+    }
+}
+
+/// Builder for configuring a [`Normalizer`]'s [`Norm`].
+///
+/// Construct one with [`Normalizer::params`].
+#[derive(Default)]
+pub struct NormalizerParams {
+    norm: Norm,
+}
+
+impl NormalizerParams {
+    /// Sets the norm used to rescale each sample. Defaults to [`Norm::L2`].
+    pub fn norm(mut self, norm: Norm) -> Self {
+        self.norm = norm;
+        self
+    }
+
+    /// Builds a `Normalizer` with the configured norm. `obs` is ignored, since `Normalizer` has
+    /// no fitted state.
+    pub fn fit(self, _obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Normalizer {
+        Normalizer { norm: self.norm }
+    }
+}
+
+impl Transformer for Normalizer {
+    /// Returns a `Normalizer` using the default [`Norm::L2`]. `obs` is ignored, since
+    /// `Normalizer` has no fitted state.
+    fn fit(obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Normalizer {
+        NormalizerParams::default().fit(obs)
+    }
+
+    /// Rescales each row of `obs` to unit norm, leaving all-zero rows unchanged.
+    ///
+    /// Returns a new owned Array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, Normalizer};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let data = array![[3., 4.], [0., 0.]];
+    /// let normalized = Normalizer::fit_transform(&data);
+    /// assert_abs_diff_eq!(normalized, array![[0.6, 0.8], [0., 0.]], epsilon = 1e-10);
+    /// ```
+    fn transform(&self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
+        let mut result = obs.to_owned();
+        for mut row in result.axis_iter_mut(Axis(0)) {
+            let norm = self.norm.of(row.as_slice().unwrap());
+            if norm != 0. {
+                row.mapv_inplace(|x| x / norm);
+            }
+        }
+        result
+    }
+
+    /// Applies `fit` and then `transform` in succession.
+    ///
+    /// Returns a new owned Array.
+    fn fit_transform(obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
+        Self::fit(obs).transform(obs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn fit_is_a_no_op() {
+        let data = array![[3., 4.]];
+        let normalizer = Normalizer::fit(&data);
+        assert_eq!(normalizer.norm(), Norm::L2);
+    }
+
+    #[test]
+    fn l2_transform_test() {
+        let data = array![[3., 4.], [1., 0.]];
+        let normalized = Normalizer::params().norm(Norm::L2).fit(&data).transform(&data);
+        assert_abs_diff_eq!(normalized, array![[0.6, 0.8], [1., 0.]], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn l1_transform_test() {
+        let data = array![[3., 4.]];
+        let normalized = Normalizer::params().norm(Norm::L1).fit(&data).transform(&data);
+        assert_abs_diff_eq!(normalized, array![[3. / 7., 4. / 7.]], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn max_transform_test() {
+        let data = array![[3., -4.]];
+        let normalized = Normalizer::params().norm(Norm::Max).fit(&data).transform(&data);
+        assert_abs_diff_eq!(normalized, array![[0.75, -1.]], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn zero_row_left_unchanged() {
+        let data = array![[0., 0.], [3., 4.]];
+        let normalized = Normalizer::fit_transform(&data);
+        assert_abs_diff_eq!(normalized, array![[0., 0.], [0.6, 0.8]], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn fit_transform_test() {
+        let data = array![[3., 4.]];
+        let normalized = Normalizer::fit_transform(&data);
+        assert_abs_diff_eq!(normalized, array![[0.6, 0.8]], epsilon = 1e-10);
+    }
+}
@@ -0,0 +1,245 @@
+pub use super::transformer::Transformer;
+use ndarray::{Array1, Array2, ArrayBase, Axis, Data, Ix2};
+use ndarray_stats::interpolate::Linear;
+use ndarray_stats::QuantileExt;
+use noisy_float::types::n64;
+use std::fmt;
+
+/// RobustScaler standardizes features by subtracting the column median and dividing by the
+/// interquartile range (IQR), so that outliers don't dominate the scaling the way they can in
+/// `StandardScaler` or `MinMaxScaler`.
+#[derive(Clone, PartialEq)]
+pub struct RobustScaler {
+    medians: Array1<f64>,
+    iqrs: Array1<f64>,
+    quantile_range: (f64, f64),
+}
+
+impl RobustScaler {
+    pub fn medians(&self) -> &Array1<f64> {
+        &self.medians
+    }
+
+    pub fn iqrs(&self) -> &Array1<f64> {
+        &self.iqrs
+    }
+
+    /// The (lower, upper) quantiles used to compute the scale, e.g. `(0.25, 0.75)` for the IQR.
+    pub fn quantile_range(&self) -> (f64, f64) {
+        self.quantile_range
+    }
+
+    /// Returns a builder for configuring the quantile range used to compute the scale, before
+    /// calling [`RobustScalerParams::fit`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::RobustScaler;
+    ///
+    /// let data = array![[1.], [2.], [3.], [4.], [5.]];
+    /// // Widen the range so more of the tails are absorbed into the scale.
+    /// let scaler = RobustScaler::params().quantile_range(0.1, 0.9).fit(&data);
+    /// ```
+    pub fn params() -> RobustScalerParams {
+        RobustScalerParams::default()
+    }
+
+    /// Maps `obs`, assumed to be in the scale produced by `transform`, back to the original
+    /// feature scale.
+    ///
+    /// Returns a new owned Array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, RobustScaler};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let data = array![[1.], [2.], [3.], [4.], [5.]];
+    /// let scaler = RobustScaler::fit(&data);
+    /// let scaled = scaler.transform(&data);
+    /// let recovered = scaler.inverse_transform(&scaled);
+    /// assert_abs_diff_eq!(recovered, data, epsilon = 1e-10);
+    /// ```
+    pub fn inverse_transform(&self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
+        obs * &self.iqrs + &self.medians
+    }
+}
+
+/// Builder for configuring a [`RobustScaler`]'s quantile range.
+///
+/// Construct one with [`RobustScaler::params`].
+pub struct RobustScalerParams {
+    quantile_range: (f64, f64),
+}
+
+impl Default for RobustScalerParams {
+    fn default() -> Self {
+        RobustScalerParams {
+            quantile_range: (0.25, 0.75),
+        }
+    }
+}
+
+impl RobustScalerParams {
+    /// Sets the (lower, upper) quantiles used to compute the scale. Defaults to `(0.25, 0.75)`,
+    /// i.e. the interquartile range.
+    ///
+    /// # Panics
+    /// This function panics if `lower` is not strictly less than `upper`, or either is outside
+    /// `[0., 1.]`.
+    pub fn quantile_range(mut self, lower: f64, upper: f64) -> Self {
+        if !(0. ..=1.).contains(&lower) || !(0. ..=1.).contains(&upper) || lower >= upper {
+            panic!("quantile_range must satisfy 0. <= lower < upper <= 1.");
+        }
+        self.quantile_range = (lower, upper);
+        self
+    }
+
+    /// Fits a `RobustScaler` to `obs`, using the configured quantile range.
+    ///
+    /// # NaN
+    /// This function ignores `NaN`s when computing quantiles.
+    ///
+    /// # Panics
+    /// This function panics if a column has an interquartile range of zero, since that results
+    /// in a NaN when transforming due to division by zero.
+    pub fn fit(self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> RobustScaler {
+        let mut owned = obs.to_owned();
+        let (lower, upper) = self.quantile_range;
+        let medians = owned
+            .quantile_axis_skipnan_mut(Axis(0), n64(0.5), &Linear)
+            .unwrap();
+        let lower_q = owned
+            .quantile_axis_skipnan_mut(Axis(0), n64(lower), &Linear)
+            .unwrap();
+        let upper_q = owned
+            .quantile_axis_skipnan_mut(Axis(0), n64(upper), &Linear)
+            .unwrap();
+        let iqrs = upper_q - lower_q;
+        if iqrs.iter().any(|iqr| *iqr == 0.) {
+            panic!("A column has an interquartile range of zero. Cannot scale due to division by zero.");
+        }
+        RobustScaler {
+            medians,
+            iqrs,
+            quantile_range: self.quantile_range,
+        }
+    }
+}
+
+impl Transformer for RobustScaler {
+    /// Returns a `RobustScaler` instance with medians and interquartile ranges derived from the
+    /// columns of `obs`, using the default quantile range of `(0.25, 0.75)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, RobustScaler};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let data = array![[1., 0.], [2., 0.], [3., 0.], [4., 1.], [5., 9.]];
+    /// let scaler = RobustScaler::fit(&data);
+    /// assert_abs_diff_eq!(*scaler.medians(), array![3., 0.], epsilon = 1e-10);
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if a column has an interquartile range of zero, since that results
+    /// in a NaN when transforming due to division by zero.
+    fn fit(obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> RobustScaler {
+        RobustScalerParams::default().fit(obs)
+    }
+
+    /// Uses the medians and interquartile ranges in `self` to scale the features of `obs`.
+    ///
+    /// Returns a new owned Array.
+    ///
+    /// For each feature x, returns (x - median) / iqr.
+    fn transform(&self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
+        (obs - &self.medians) / &self.iqrs
+    }
+
+    /// Applies `fit` and then `transform` in succession.
+    ///
+    /// Returns a new owned Array.
+    ///
+    /// # Panics
+    /// This function panics if a column has an interquartile range of zero, since that results
+    /// in a NaN when transforming due to division by zero.
+    fn fit_transform(obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
+        Self::fit(obs).transform(obs)
+    }
+}
+
+impl fmt::Display for RobustScaler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Medians: {}\nIQRs: {}", self.medians, self.iqrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn fit_test() {
+        let data = array![[1., 0.], [2., 0.], [3., 0.], [4., 1.], [5., 9.]];
+        let scaler = RobustScaler::fit(&data);
+        assert_abs_diff_eq!(*scaler.medians(), array![3., 0.], epsilon = 1e-10);
+        assert_abs_diff_eq!(*scaler.iqrs(), array![2., 1.], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn transform_test() {
+        let data = array![[1.], [2.], [3.], [4.], [5.]];
+        let scaler = RobustScaler::fit(&data);
+        let scaled = scaler.transform(&data);
+        assert_abs_diff_eq!(scaled, array![[-1.], [-0.5], [0.], [0.5], [1.]], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn fit_transform_test() {
+        let data = array![[1.], [2.], [3.], [4.], [5.]];
+        let scaled = RobustScaler::fit_transform(&data);
+        assert_abs_diff_eq!(scaled, array![[-1.], [-0.5], [0.], [0.5], [1.]], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn inverse_transform_test() {
+        let data = array![[1., 0.], [2., 0.], [3., 0.], [4., 1.], [5., 9.]];
+        let scaler = RobustScaler::fit(&data);
+        let scaled = scaler.transform(&data);
+        let recovered = scaler.inverse_transform(&scaled);
+        assert_abs_diff_eq!(recovered, data, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn custom_quantile_range_test() {
+        let data = array![[1.], [2.], [3.], [4.], [5.]];
+        let scaler = RobustScaler::params().quantile_range(0.1, 0.9).fit(&data);
+        assert_abs_diff_eq!(*scaler.medians(), array![3.], epsilon = 1e-10);
+        assert_abs_diff_eq!(*scaler.iqrs(), array![3.2], epsilon = 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "quantile_range must satisfy 0. <= lower < upper <= 1.")]
+    fn invalid_quantile_range_panics() {
+        RobustScaler::params().quantile_range(0.75, 0.25);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "A column has an interquartile range of zero. Cannot scale due to division by zero."
+    )]
+    fn zero_iqr_fit_panics() {
+        // first column is constant
+        let data = array![[1., 0.], [1., 1.], [1., 2.], [1., 3.]];
+        RobustScaler::fit(&data);
+    }
+}
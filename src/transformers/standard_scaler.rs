@@ -2,14 +2,37 @@ pub use super::transformer::Transformer;
 use ndarray::{Array1, Array2, ArrayBase, Axis, Data, Ix2};
 use std::fmt;
 
-#[derive(Clone, PartialEq, Default)]
+#[derive(Clone, PartialEq)]
 /// StandardScalar standardizes features by subtracting the mean and dividing by the sample standard deviation.
 /// This results in features with zero mean and unit variance.
-// TODO: Allow computation without mean (just scale) or without stddev (just center)
-// TODO: Allow for online computation (partial_fit)
 pub struct StandardScaler {
     means: Array1<f64>,
     stds: Array1<f64>,
+    // Sum of squared deviations from the running mean (Chan's `M2`), kept around so that
+    // `partial_fit` can keep merging batches without revisiting earlier ones.
+    m2: Array1<f64>,
+    n_samples_seen: usize,
+    with_mean: bool,
+    with_std: bool,
+    target_mean: f64,
+    target_std: f64,
+}
+
+impl Default for StandardScaler {
+    /// The default scaler both centers and scales onto a target mean of 0 and target standard
+    /// deviation of 1, matching `StandardScaler::fit`.
+    fn default() -> Self {
+        StandardScaler {
+            means: Array1::zeros(0),
+            stds: Array1::zeros(0),
+            m2: Array1::zeros(0),
+            n_samples_seen: 0,
+            with_mean: true,
+            with_std: true,
+            target_mean: 0.,
+            target_std: 1.,
+        }
+    }
 }
 
 impl StandardScaler {
@@ -20,6 +43,132 @@ impl StandardScaler {
     pub fn stds(&self) -> &Array1<f64> {
         &self.stds
     }
+
+    /// The total number of samples folded into this scaler across all `partial_fit` calls.
+    pub fn n_samples_seen(&self) -> usize {
+        self.n_samples_seen
+    }
+
+    /// Returns a builder for configuring center-only or scale-only standardization before
+    /// calling [`StandardScalerParams::fit`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, StandardScaler};
+    ///
+    /// let data = array![[2., 0.], [0., 2.]];
+    /// // Center only: divide-by-std is skipped, so the scale of the original data is kept.
+    /// let std_sclr = StandardScaler::params().with_std(false).fit(&data);
+    /// assert_eq!(std_sclr.transform(&data), array![[1., -1.], [-1., 1.]]);
+    /// ```
+    pub fn params() -> StandardScalerParams {
+        StandardScalerParams::default()
+    }
+
+    /// Maps `obs`, assumed to be in the scale produced by `transform`, back to the original
+    /// feature scale.
+    ///
+    /// Returns a new owned Array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, StandardScaler};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let data = array![[2., 0.], [0., 2.]];
+    /// let std_sclr = StandardScaler::fit(&data);
+    /// let standardized = std_sclr.transform(&data);
+    /// let recovered = std_sclr.inverse_transform(&standardized);
+    /// assert_abs_diff_eq!(recovered, data, epsilon = 1e-10);
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if `with_std` is `true` and a column's standard deviation is zero or
+    /// `NaN`, since either silently divides by zero (see [`StandardScaler::partial_fit`]).
+    pub fn inverse_transform(&self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
+        self.check_stds();
+        let unscaled = if self.with_mean {
+            obs - self.target_mean
+        } else {
+            obs.to_owned()
+        };
+        let unscaled = if self.with_std {
+            unscaled / self.target_std * &self.stds
+        } else {
+            unscaled
+        };
+        if self.with_mean {
+            unscaled + &self.means
+        } else {
+            unscaled
+        }
+    }
+
+    /// Updates the running per-column mean and standard deviation with another batch of
+    /// observations, without needing to revisit batches seen so far.
+    ///
+    /// This uses Chan's parallel variance merge: the batch's own count, mean and sum of squared
+    /// deviations (`M2`) are combined with the running aggregate, which keeps the computation
+    /// numerically stable across many batches instead of accumulating a single running sum of
+    /// squares. `fit` is just `partial_fit` called once on a zero-initialized scaler.
+    ///
+    /// `partial_fit` itself never panics, since a batch that leaves `n_samples_seen` at 1 (or a
+    /// column's standard deviation at zero) is a normal, transient state for a scaler that is
+    /// still accumulating batches. `transform`/`inverse_transform` panic instead, at the point
+    /// where that state would otherwise silently produce `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use linfa_preprocessing::transformers::{Transformer, StandardScaler};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let mut std_sclr = StandardScaler::default();
+    /// std_sclr.partial_fit(&array![[2., 0.], [0., 2.]]);
+    /// std_sclr.partial_fit(&array![[4., -2.]]);
+    ///
+    /// assert_eq!(std_sclr.n_samples_seen(), 3);
+    /// assert_abs_diff_eq!(*std_sclr.means(), array![2., 0.], epsilon = 1e-10);
+    /// ```
+    pub fn partial_fit(&mut self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) {
+        let n_b = obs.nrows();
+        if n_b == 0 {
+            return;
+        }
+        let mean_b = obs.mean_axis(Axis(0)).unwrap();
+        // Population variance (ddof = 0) times n_b is the sum of squared deviations.
+        let m2_b = obs.var_axis(Axis(0), 0.) * n_b as f64;
+
+        if self.n_samples_seen == 0 {
+            self.means = mean_b;
+            self.m2 = m2_b;
+        } else {
+            let n_a = self.n_samples_seen;
+            let n = n_a + n_b;
+            let delta = &mean_b - &self.means;
+            self.means = &self.means + &delta * (n_b as f64 / n as f64);
+            self.m2 = &self.m2 + &m2_b + delta.mapv(|d| d * d) * (n_a as f64 * n_b as f64 / n as f64);
+        }
+        self.n_samples_seen += n_b;
+        self.stds = (&self.m2 / (self.n_samples_seen as f64 - 1.)).mapv(f64::sqrt);
+    }
+
+    /// # Panics
+    /// Panics if `with_std` is set and a column's standard deviation is zero or `NaN` (the
+    /// latter happens when `partial_fit` has seen fewer than 2 samples), since either would
+    /// silently divide by zero or propagate `NaN` into `transform`/`inverse_transform`.
+    fn check_stds(&self) {
+        if self.with_std && self.stds.iter().any(|std| *std == 0. || std.is_nan()) {
+            // TODO: Tell user which column(s) have stddev of zero.
+            // Should this panic or deal with the error in another way?
+            panic!("A column has a standard deviation of zero. Cannot standardize due to divison by zero.");
+        }
+    }
 }
 
 impl Transformer for StandardScaler {
@@ -44,17 +193,7 @@ impl Transformer for StandardScaler {
     /// This function panics if a column is constant.
     /// This is because the column will have a standard deviation of zero, which results in a NaN when transforming due to division by zero.
     fn fit(obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> StandardScaler {
-        // Using sample standard deviation (ddof = 1)
-        let stds = obs.std_axis(Axis(0), 1.);
-        if stds.iter().any(|std| *std == 0.) {
-            // TODO: Tell user which column(s) have stddev of zero.
-            // Should this panic or deal with the error in another way?
-            panic!("A column has a standard deviation of zero. Cannot standardize due to divison by zero.");
-        }
-        StandardScaler {
-            means: obs.mean_axis(Axis(0)).unwrap(),
-            stds,
-        }
+        StandardScalerParams::default().fit(obs)
     }
 
     /// Uses the means and standard deviations in `self` to standardize the features of obs.
@@ -75,10 +214,36 @@ impl Transformer for StandardScaler {
     /// assert_abs_diff_eq!(standardized, array![[0.707107, -0.707107], [-0.707107, 0.707107]], epsilon=1e-5);
     /// ```
     ///
-    /// Standardization is calculated as z = (x - m) / s where z is the resulting standardized feature,
-    /// m is the original feature mean and s is the original feature sample standard deviation.
+    /// Standardization is calculated as z = (x - m) / s * s_t + m_t, where z is the resulting
+    /// standardized feature, m is the original feature mean, s is the original feature sample
+    /// standard deviation, and m_t/s_t are the target mean/standard deviation (0 and 1 unless
+    /// configured via [`StandardScalerParams::target_mean`]/[`StandardScalerParams::target_std`]).
+    ///
+    /// If this scaler was built with [`StandardScalerParams::with_mean`] or
+    /// [`StandardScalerParams::with_std`] set to `false`, the corresponding step (along with its
+    /// target) is skipped entirely.
+    ///
+    /// # Panics
+    /// This function panics if `with_std` is `true` and a column's standard deviation is zero or
+    /// `NaN`, since either silently divides by zero. This can happen if this scaler was built
+    /// from [`StandardScaler::partial_fit`] directly and too few samples have been seen so far.
     fn transform(&self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> Array2<f64> {
-        (obs - &self.means) / &self.stds
+        self.check_stds();
+        let centered = if self.with_mean {
+            obs - &self.means
+        } else {
+            obs.to_owned()
+        };
+        let scaled = if self.with_std {
+            centered / &self.stds * self.target_std
+        } else {
+            centered
+        };
+        if self.with_mean {
+            scaled + self.target_mean
+        } else {
+            scaled
+        }
     }
 
     /// Applies `fit` and then `transform` in succession.
@@ -105,6 +270,90 @@ impl Transformer for StandardScaler {
     }
 }
 
+/// Builder for configuring a [`StandardScaler`] that centers only, scales only, targets a custom
+/// mean/standard deviation, or (the default) centers and scales onto a mean of 0 and a standard
+/// deviation of 1.
+///
+/// Construct one with [`StandardScaler::params`].
+pub struct StandardScalerParams {
+    with_mean: bool,
+    with_std: bool,
+    target_mean: f64,
+    target_std: f64,
+}
+
+impl Default for StandardScalerParams {
+    fn default() -> Self {
+        StandardScalerParams {
+            with_mean: true,
+            with_std: true,
+            target_mean: 0.,
+            target_std: 1.,
+        }
+    }
+}
+
+impl StandardScalerParams {
+    /// If `false`, `transform` will not subtract the column mean. Defaults to `true`.
+    ///
+    /// Useful for preserving a meaningful zero, e.g. when standardizing sparse data.
+    pub fn with_mean(mut self, with_mean: bool) -> Self {
+        self.with_mean = with_mean;
+        self
+    }
+
+    /// If `false`, `transform` will not divide by the column standard deviation. Defaults to `true`.
+    pub fn with_std(mut self, with_std: bool) -> Self {
+        self.with_std = with_std;
+        self
+    }
+
+    /// The mean that `transform` maps each feature onto, instead of 0. Defaults to `0.`.
+    ///
+    /// Useful for standardizing several datasets onto a common reference distribution.
+    pub fn target_mean(mut self, target_mean: f64) -> Self {
+        self.target_mean = target_mean;
+        self
+    }
+
+    /// The standard deviation that `transform` maps each feature onto, instead of 1. Defaults to
+    /// `1.`.
+    ///
+    /// # Panics
+    /// This function panics if `target_std` is zero, since that would collapse every feature to
+    /// its target mean and make `inverse_transform` divide by zero.
+    pub fn target_std(mut self, target_std: f64) -> Self {
+        if target_std == 0. {
+            panic!("target_std must not be zero.");
+        }
+        self.target_std = target_std;
+        self
+    }
+
+    /// Fits a `StandardScaler` to `obs`, honoring the `with_mean`/`with_std`/`target_mean`/
+    /// `target_std` settings.
+    ///
+    /// # Panics
+    /// This function panics if `with_std` is `true` and a column is constant, since that column
+    /// would have a standard deviation of zero, resulting in a NaN when transforming due to
+    /// division by zero. It also panics if fewer than 2 samples were seen (and there is at least
+    /// one column to standardize), since the sample standard deviation (ddof = 1) is undefined
+    /// for a single sample and would otherwise come out as `NaN` rather than `0.`, silently
+    /// slipping past this same guard.
+    pub fn fit(self, obs: &ArrayBase<impl Data<Elem = f64>, Ix2>) -> StandardScaler {
+        let mut scaler = StandardScaler {
+            with_mean: self.with_mean,
+            with_std: self.with_std,
+            target_mean: self.target_mean,
+            target_std: self.target_std,
+            ..StandardScaler::default()
+        };
+        scaler.partial_fit(obs);
+        scaler.check_stds();
+        scaler
+    }
+}
+
 impl fmt::Display for StandardScaler {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Means: {}\nStds: {}", self.means, self.stds)
@@ -177,4 +426,174 @@ mod tests {
         let data = array![[1., 1., 1.], [2., 3., 1.,]];
         StandardScaler::fit_transform(&data);
     }
+
+    #[test]
+    #[should_panic(
+        expected = "A column has a standard deviation of zero. Cannot standardize due to divison by zero."
+    )]
+    fn single_sample_fit_panics_instead_of_leaking_nan_stds() {
+        // A single row has an undefined (NaN) sample standard deviation (ddof = 1), not zero.
+        StandardScaler::fit(&array![[1., 2., 3.]]);
+    }
+
+    #[test]
+    fn partial_fit_matches_fit_on_concatenated_batches() {
+        let data = array![[1., 3., 2.], [0., 0., 1.], [2., 0., 3.], [4., -2., 5.]];
+        let fit_all = StandardScaler::fit(&data);
+
+        let mut partial = StandardScaler::default();
+        partial.partial_fit(&data.slice(ndarray::s![0..2, ..]));
+        partial.partial_fit(&data.slice(ndarray::s![2..4, ..]));
+
+        assert_eq!(partial.n_samples_seen(), 4);
+        assert_abs_diff_eq!(*partial.means(), *fit_all.means(), epsilon = 1e-10);
+        assert_abs_diff_eq!(*partial.stds(), *fit_all.stds(), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn partial_fit_accumulates_n_samples_seen() {
+        let mut scaler = StandardScaler::default();
+        scaler.partial_fit(&array![[1., 2.]]);
+        scaler.partial_fit(&array![[3., 4.], [5., 6.]]);
+        assert_eq!(scaler.n_samples_seen(), 3);
+    }
+
+    #[test]
+    fn partial_fit_ignores_empty_batch() {
+        let mut scaler = StandardScaler::default();
+        scaler.partial_fit(&array![[1., 2.], [3., 4.]]);
+        let empty: Array2<f64> = Array2::zeros((0, 2));
+        scaler.partial_fit(&empty);
+        assert_eq!(scaler.n_samples_seen(), 2);
+        assert_abs_diff_eq!(*scaler.means(), array![2., 3.], epsilon = 1e-10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "A column has a standard deviation of zero. Cannot standardize due to divison by zero."
+    )]
+    fn transform_after_single_row_partial_fit_panics_instead_of_leaking_nan() {
+        let mut scaler = StandardScaler::default();
+        scaler.partial_fit(&array![[1., 2.]]);
+        scaler.transform(&array![[1., 2.]]);
+    }
+
+    #[test]
+    fn partial_fit_itself_never_panics_on_a_single_row() {
+        // partial_fit is a streaming building block; it must stay usable one row at a time
+        // even though the scaler isn't yet in a state `transform` would accept.
+        let mut scaler = StandardScaler::default();
+        scaler.partial_fit(&array![[1., 2.]]);
+        assert!(scaler.stds().iter().all(|std| std.is_nan()));
+    }
+
+    #[test]
+    fn inverse_transform_test() {
+        let data = array![[1., 3., 2.], [0., 0., 1.], [2., 0., 3.]];
+        let scaler = StandardScaler::fit(&data);
+        let standardized = scaler.transform(&data);
+        let recovered = scaler.inverse_transform(&standardized);
+        assert_abs_diff_eq!(recovered, data, epsilon = 1e-10);
+    }
+
+    mod params_tests {
+        use super::*;
+
+        #[test]
+        fn with_std_false_centers_only() {
+            let data = array![[2., 0.], [0., 2.]];
+            let scaler = StandardScaler::params().with_std(false).fit(&data);
+            let scaled = scaler.transform(&data);
+            assert_abs_diff_eq!(scaled, array![[1., -1.], [-1., 1.]], epsilon = 1e-10);
+        }
+
+        #[test]
+        fn with_mean_false_scales_only() {
+            let data = array![[2., 0.], [0., 2.]];
+            let scaler = StandardScaler::params().with_mean(false).fit(&data);
+            let scaled = scaler.transform(&data);
+            let sqrt_2 = std::f64::consts::SQRT_2;
+            assert_abs_diff_eq!(
+                scaled,
+                array![[sqrt_2, 0.], [0., sqrt_2]],
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn default_params_match_fit() {
+            let data = array![[1., 3., 2.], [0., 0., 1.], [2., 0., 3.]];
+            let via_params = StandardScaler::params().fit(&data);
+            let via_fit = StandardScaler::fit(&data);
+            assert_abs_diff_eq!(*via_params.means(), *via_fit.means(), epsilon = 1e-10);
+            assert_abs_diff_eq!(*via_params.stds(), *via_fit.stds(), epsilon = 1e-10);
+        }
+
+        #[test]
+        fn inverse_transform_round_trips_with_std_false() {
+            let data = array![[2., 0.], [0., 2.]];
+            let scaler = StandardScaler::params().with_std(false).fit(&data);
+            let centered = scaler.transform(&data);
+            let recovered = scaler.inverse_transform(&centered);
+            assert_abs_diff_eq!(recovered, data, epsilon = 1e-10);
+        }
+
+        #[test]
+        fn with_std_false_ignores_zero_stddev_column() {
+            // Third column is constant; with_std(false) never divides by its stddev.
+            let data = array![[1., 1., 1.], [2., 3., 1.]];
+            let scaler = StandardScaler::params().with_std(false).fit(&data);
+            assert_abs_diff_eq!(*scaler.means(), array![1.5, 2., 1.], epsilon = 1e-10);
+        }
+
+        #[test]
+        fn target_mean_and_std_shift_the_standardized_distribution() {
+            let data = array![[1., 3., 2.], [0., 0., 1.], [2., 0., 3.]];
+            let scaler = StandardScaler::params()
+                .target_mean(100.)
+                .target_std(10.)
+                .fit(&data);
+            let scaled = scaler.transform(&data);
+            // The target mean/std should now describe the scaled distribution's own mean/std.
+            assert_abs_diff_eq!(
+                scaled.mean_axis(Axis(0)).unwrap(),
+                array![100., 100., 100.],
+                epsilon = 1e-10
+            );
+            assert_abs_diff_eq!(
+                scaled.std_axis(Axis(0), 1.),
+                array![10., 10., 10.],
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn with_std_false_ignores_target_std() {
+            let data = array![[2., 0.], [0., 2.]];
+            let scaler = StandardScaler::params()
+                .with_std(false)
+                .target_std(10.)
+                .fit(&data);
+            let scaled = scaler.transform(&data);
+            assert_abs_diff_eq!(scaled, array![[1., -1.], [-1., 1.]], epsilon = 1e-10);
+        }
+
+        #[test]
+        #[should_panic(expected = "target_std must not be zero.")]
+        fn target_std_zero_panics() {
+            StandardScaler::params().target_std(0.);
+        }
+
+        #[test]
+        fn inverse_transform_round_trips_with_target_mean_and_std() {
+            let data = array![[1., 3., 2.], [0., 0., 1.], [2., 0., 3.]];
+            let scaler = StandardScaler::params()
+                .target_mean(5.)
+                .target_std(2.)
+                .fit(&data);
+            let scaled = scaler.transform(&data);
+            let recovered = scaler.inverse_transform(&scaled);
+            assert_abs_diff_eq!(recovered, data, epsilon = 1e-10);
+        }
+    }
 }